@@ -0,0 +1,77 @@
+// Auto-generated from thesis_complete.py
+//! Standalone OTLP export path for the thesis spans: lets `emit_thesis_spans`
+//! run outside a host application that already wires up a `TracerProvider`.
+
+use std::fmt;
+
+use opentelemetry::{global, trace::TracerProvider as _};
+use opentelemetry_otlp::{Protocol, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{runtime, trace::TracerProvider};
+
+use crate::spans::emit_thesis_spans;
+
+/// The wire protocol used to ship spans to the collector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OtlpProtocol {
+    Grpc,
+    HttpProtobuf,
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    Runtime(std::io::Error),
+    Build(opentelemetry::trace::TraceError),
+    Shutdown(opentelemetry::trace::TraceError),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Runtime(err) => write!(f, "failed to start export runtime: {err}"),
+            ExportError::Build(err) => write!(f, "failed to build OTLP exporter: {err}"),
+            ExportError::Shutdown(err) => write!(f, "failed to shut down tracer provider: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+/// Build a batch OTLP exporter pointed at `endpoint` over the requested
+/// `protocol`, emit the thesis span DAG through it, then flush and shut the
+/// provider down so every span is guaranteed to have been sent before
+/// returning.
+///
+/// The batch processor needs a Tokio reactor to schedule its background
+/// export task on, so this spins up its own multi-threaded Tokio runtime rather
+/// than requiring the caller to already be inside one — that's what makes
+/// it callable standalone from a plain `fn main()`.
+pub fn export_thesis_otlp(endpoint: &str, protocol: OtlpProtocol) -> Result<(), ExportError> {
+    let runtime = tokio::runtime::Runtime::new().map_err(ExportError::Runtime)?;
+
+    runtime.block_on(async {
+        let exporter = match protocol {
+            OtlpProtocol::Grpc => SpanExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint)
+                .with_protocol(Protocol::Grpc)
+                .build(),
+            OtlpProtocol::HttpProtobuf => SpanExporter::builder()
+                .with_http()
+                .with_endpoint(endpoint)
+                .with_protocol(Protocol::HttpBinary)
+                .build(),
+        }
+        .map_err(ExportError::Build)?;
+
+        let provider = TracerProvider::builder()
+            .with_batch_exporter(exporter, runtime::Tokio)
+            .build();
+
+        let tracer = provider.tracer("swarmsh.thesis");
+        global::set_tracer_provider(provider.clone());
+
+        emit_thesis_spans(&tracer);
+
+        provider.shutdown().map_err(ExportError::Shutdown)
+    })
+}
@@ -0,0 +1,186 @@
+// Auto-generated from thesis_complete.py
+//! Tags spans with message-passing roles (entry/exit/local) and messaging
+//! attributes, and links an `Entry` span back to the `Exit` span's context
+//! it correlates with, so a cross-agent message exchange shows up as one
+//! connected trace.
+
+use opentelemetry::{
+    trace::{Link, Span, SpanContext, Tracer},
+    Key, KeyValue,
+};
+
+const SPAN_ROLE: Key = Key::from_static_str("swarmsh.span_role");
+const MESSAGING_SYSTEM: Key = Key::from_static_str("messaging.system");
+const MESSAGING_DESTINATION: Key = Key::from_static_str("messaging.destination");
+
+/// The role a span plays in the agent messaging bus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpanRole {
+    /// A message received from another agent.
+    Entry,
+    /// A message sent to `peer`. Its `SpanContext` is the correlation id an
+    /// `Entry` span on the peer should link back to.
+    Exit,
+    /// Work local to this agent, not itself a message boundary.
+    Local,
+}
+
+impl SpanRole {
+    fn as_str(self) -> &'static str {
+        match self {
+            SpanRole::Entry => "entry",
+            SpanRole::Exit => "exit",
+            SpanRole::Local => "local",
+        }
+    }
+}
+
+/// Emit a span carrying message-bus semantics and return its `SpanContext`
+/// for correlation. For an `Exit` span, pass the returned context to the
+/// peer's matching `Entry` call as `correlates_with` so the two link up into
+/// one cross-agent trace.
+pub fn emit_message_span(
+    tracer: &dyn Tracer,
+    role: SpanRole,
+    peer: &str,
+    payload: &[KeyValue],
+    correlates_with: Option<SpanContext>,
+) -> SpanContext {
+    let mut attributes = vec![
+        KeyValue::new(SPAN_ROLE, role.as_str()),
+        KeyValue::new(MESSAGING_SYSTEM, "swarmsh"),
+    ];
+    if matches!(role, SpanRole::Exit) {
+        attributes.push(KeyValue::new(MESSAGING_DESTINATION, peer.to_string()));
+    }
+    attributes.extend(payload.iter().cloned());
+
+    let span_name = match role {
+        SpanRole::Entry => format!("swarmsh.message.entry.{peer}"),
+        SpanRole::Exit => format!("swarmsh.message.exit.{peer}"),
+        SpanRole::Local => "swarmsh.message.local".to_string(),
+    };
+
+    let links = correlates_with
+        .map(|ctx| vec![Link::new(ctx, vec![KeyValue::new("swarmsh.edge", "correlates")])])
+        .unwrap_or_default();
+
+    let span = tracer
+        .span_builder(span_name)
+        .with_attributes(attributes)
+        .with_links(links)
+        .start(tracer);
+    let context = span.span_context().clone();
+    span.end();
+    context
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use opentelemetry::trace::{TraceResult, TracerProvider as _};
+    use opentelemetry::Context;
+    use opentelemetry_sdk::export::trace::SpanData;
+    use opentelemetry_sdk::trace::{Span, SpanProcessor, TracerProvider};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct RecordingProcessor {
+        recorded: Arc<Mutex<Vec<SpanData>>>,
+    }
+
+    impl SpanProcessor for RecordingProcessor {
+        fn on_start(&self, _span: &mut Span, _cx: &Context) {}
+
+        fn on_end(&self, span: SpanData) {
+            self.recorded.lock().unwrap().push(span);
+        }
+
+        fn force_flush(&self) -> TraceResult<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> TraceResult<()> {
+            Ok(())
+        }
+    }
+
+    fn attr(span: &SpanData, key: &str) -> Option<String> {
+        span.attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| kv.value.as_str().into_owned())
+    }
+
+    #[test]
+    fn exit_span_records_destination_and_returned_context_matches_the_span() {
+        let processor = RecordingProcessor::default();
+        let recorded = processor.recorded.clone();
+        let provider = TracerProvider::builder()
+            .with_span_processor(processor)
+            .build();
+        let tracer = provider.tracer("swarmsh.thesis.tests");
+
+        let context = emit_message_span(&tracer, SpanRole::Exit, "agent-b", &[], None);
+        let _ = provider.shutdown();
+
+        let spans = recorded.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(attr(&spans[0], "swarmsh.span_role").as_deref(), Some("exit"));
+        assert_eq!(
+            attr(&spans[0], "messaging.destination").as_deref(),
+            Some("agent-b")
+        );
+        assert_eq!(spans[0].span_context.span_id(), context.span_id());
+    }
+
+    #[test]
+    fn local_span_has_no_destination_attribute() {
+        let processor = RecordingProcessor::default();
+        let recorded = processor.recorded.clone();
+        let provider = TracerProvider::builder()
+            .with_span_processor(processor)
+            .build();
+        let tracer = provider.tracer("swarmsh.thesis.tests");
+
+        emit_message_span(&tracer, SpanRole::Local, "unused", &[], None);
+        let _ = provider.shutdown();
+
+        let spans = recorded.lock().unwrap();
+        assert_eq!(attr(&spans[0], "swarmsh.span_role").as_deref(), Some("local"));
+        assert_eq!(attr(&spans[0], "messaging.destination"), None);
+    }
+
+    #[test]
+    fn entry_span_links_back_to_the_correlated_exit_context() {
+        let processor = RecordingProcessor::default();
+        let recorded = processor.recorded.clone();
+        let provider = TracerProvider::builder()
+            .with_span_processor(processor)
+            .build();
+        let tracer = provider.tracer("swarmsh.thesis.tests");
+
+        let exit_context = emit_message_span(&tracer, SpanRole::Exit, "agent-b", &[], None);
+        let _ = emit_message_span(
+            &tracer,
+            SpanRole::Entry,
+            "agent-a",
+            &[],
+            Some(exit_context.clone()),
+        );
+        let _ = provider.shutdown();
+
+        let spans = recorded.lock().unwrap();
+        let entry_span = spans
+            .iter()
+            .find(|span| attr(span, "swarmsh.span_role").as_deref() == Some("entry"))
+            .expect("entry span should have been recorded");
+        assert_eq!(entry_span.links.len(), 1);
+        assert_eq!(
+            entry_span.links[0].span_context.span_id(),
+            exit_context.span_id()
+        );
+    }
+}
@@ -0,0 +1,164 @@
+// Auto-generated from thesis_complete.py
+//! Realizes the `trace_to_prompt_emergence` claim ("Traces → LLM prompts"):
+//! captures the thesis span DAG as it's emitted and synthesizes an LLM
+//! prompt from the captured structure, closing the telemetry → prompt loop.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use opentelemetry::trace::{SpanId, TraceResult, Tracer, TracerProvider as _};
+use opentelemetry::Context;
+use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_sdk::trace::{Span, SpanProcessor, TracerProvider};
+
+use crate::spans::emit_thesis_spans;
+
+/// One span as captured off the wire: its name, its `brief` attribute, and
+/// the names of the claims it links back to.
+#[derive(Clone, Debug)]
+pub struct CapturedSpan {
+    pub name: String,
+    pub brief: String,
+    pub links: Vec<String>,
+}
+
+/// A `SpanProcessor` that copies every span it sees into a shared buffer as
+/// a `CapturedSpan`. Link targets are resolved to claim names via a
+/// span-id-to-name map built up as spans complete, so `on_end` must see a
+/// span's parents before it sees the span itself.
+#[derive(Clone, Default)]
+struct CapturingProcessor {
+    captured: Arc<Mutex<Vec<CapturedSpan>>>,
+    names_by_span_id: Arc<Mutex<HashMap<SpanId, String>>>,
+}
+
+impl SpanProcessor for CapturingProcessor {
+    fn on_start(&self, _span: &mut Span, _cx: &Context) {}
+
+    fn on_end(&self, span: SpanData) {
+        let brief = span
+            .attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == "brief")
+            .map(|kv| kv.value.as_str().into_owned())
+            .unwrap_or_default();
+
+        let mut names_by_span_id = self.names_by_span_id.lock().unwrap();
+        names_by_span_id.insert(span.span_context.span_id(), span.name.to_string());
+
+        let links = span
+            .links
+            .iter()
+            .filter_map(|link| names_by_span_id.get(&link.span_context.span_id()).cloned())
+            .collect();
+        drop(names_by_span_id);
+
+        self.captured.lock().unwrap().push(CapturedSpan {
+            name: span.name.to_string(),
+            brief,
+            links,
+        });
+    }
+
+    fn force_flush(&self) -> TraceResult<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> TraceResult<()> {
+        Ok(())
+    }
+}
+
+/// Emits the thesis spans through an in-memory `CapturingProcessor` instead
+/// of an exporter, and returns the structured record it built.
+pub fn capture_thesis_spans() -> Vec<CapturedSpan> {
+    let processor = CapturingProcessor::default();
+    let captured = processor.captured.clone();
+
+    let provider = TracerProvider::builder()
+        .with_span_processor(processor)
+        .build();
+    let tracer = provider.tracer("swarmsh.thesis");
+
+    emit_thesis_spans(&tracer);
+    let _ = provider.shutdown();
+
+    captured.lock().unwrap().clone()
+}
+
+/// Walks the captured DAG in dependency order and synthesizes a deterministic
+/// prompt: one plain bullet per claim without links, and one "because X,
+/// therefore Y" bullet per link edge. Links to names not in `spans` are
+/// skipped, so a claim whose links all dangle produces no bullet.
+pub fn spans_to_prompt(spans: &[CapturedSpan]) -> String {
+    let by_name: HashMap<&str, &CapturedSpan> =
+        spans.iter().map(|s| (s.name.as_str(), s)).collect();
+
+    let mut lines = Vec::new();
+    for span in spans {
+        if span.links.is_empty() {
+            lines.push(format!("- {}", span.brief));
+            continue;
+        }
+        for link in &span.links {
+            if let Some(parent) = by_name.get(link.as_str()) {
+                lines.push(format!("- because {}, therefore {}", parent.brief, span.brief));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(name: &str, brief: &str, links: &[&str]) -> CapturedSpan {
+        CapturedSpan {
+            name: name.to_string(),
+            brief: brief.to_string(),
+            links: links.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn root_claim_renders_as_a_plain_bullet() {
+        let spans = [span("root", "Telemetry is the system.", &[])];
+        assert_eq!(spans_to_prompt(&spans), "- Telemetry is the system.");
+    }
+
+    #[test]
+    fn linked_claim_renders_as_a_because_therefore_clause() {
+        let spans = [
+            span("root", "Telemetry is the system.", &[]),
+            span("child", "Spans generate code.", &["root"]),
+        ];
+        assert_eq!(
+            spans_to_prompt(&spans),
+            "- Telemetry is the system.\n\
+             - because Telemetry is the system., therefore Spans generate code."
+        );
+    }
+
+    #[test]
+    fn dangling_link_is_skipped_rather_than_panicking() {
+        let spans = [span("child", "Spans generate code.", &["missing-parent"])];
+        assert_eq!(spans_to_prompt(&spans), "");
+    }
+
+    #[test]
+    fn multiple_links_each_produce_their_own_clause() {
+        let spans = [
+            span("a", "Claim A.", &[]),
+            span("b", "Claim B.", &[]),
+            span("child", "Claim C.", &["a", "b"]),
+        ];
+        assert_eq!(
+            spans_to_prompt(&spans),
+            "- Claim A.\n\
+             - Claim B.\n\
+             - because Claim A., therefore Claim C.\n\
+             - because Claim B., therefore Claim C."
+        );
+    }
+}
@@ -1,45 +1,315 @@
 // Auto-generated from thesis_complete.py
-use opentelemetry::{trace::Tracer, KeyValue};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use opentelemetry::{
+    trace::{Link, Span, SpanContext, Tracer},
+    Key, KeyValue,
+};
+
+const BRIEF: Key = Key::from_static_str("brief");
+const EDGE: Key = Key::from_static_str("swarmsh.edge");
+const CODE_FILEPATH: Key = Key::from_static_str("code.filepath");
+const CODE_FUNCTION: Key = Key::from_static_str("code.function");
+const CODE_LINENO: Key = Key::from_static_str("code.lineno");
+const THREAD_SEQ: Key = Key::from_static_str("swarmsh.thread.seq");
+const THREAD_NAME: Key = Key::from_static_str("thread.name");
+
+/// A timestamped, named event attached to a claim's span, added between
+/// its start and end.
+pub struct ThesisEvent {
+    pub name: &'static str,
+    /// Offset from span start this event is recorded at.
+    pub ts_offset: Duration,
+    pub fields: &'static [(&'static str, &'static str)],
+}
+
+/// One claim in the thesis trace graph, and the parent claims it derives from.
+pub struct ThesisSpan {
+    pub name: &'static str,
+    pub brief: &'static str,
+    /// Names of the claims this one links back to (its parents in the DAG).
+    pub links: &'static [&'static str],
+    /// Where in `thesis_complete.py` this claim was generated from.
+    pub code_filepath: &'static str,
+    pub code_function: &'static str,
+    pub code_lineno: u32,
+    /// Evidence events supporting this claim, emitted between span start
+    /// and end so an event-aware backend can show the reasoning behind it.
+    pub events: &'static [ThesisEvent],
+}
+
+/// The thesis trace graph, in topological order: every claim's parents appear
+/// before it, so a single pass can resolve `links` against spans already seen.
+static THESIS_SPANS: &[ThesisSpan] = &[
+    ThesisSpan {
+        name: "swarmsh.thesis.telemetry_as_system",
+        brief: "Telemetry is the system, not an add-on.",
+        links: &[],
+        code_filepath: "thesis_complete.py",
+        code_function: "telemetry_as_system",
+        code_lineno: 12,
+        events: &[ThesisEvent {
+            name: "precedes-logging",
+            ts_offset: Duration::from_millis(0),
+            fields: &[("evidence", "spans emitted before any log line is written")],
+        }],
+    },
+    ThesisSpan {
+        name: "swarmsh.thesis.span_drives_code",
+        brief: "Spans generate code & CLI.",
+        links: &["swarmsh.thesis.telemetry_as_system"],
+        code_filepath: "thesis_complete.py",
+        code_function: "span_drives_code",
+        code_lineno: 24,
+        events: &[ThesisEvent {
+            name: "self-generated",
+            ts_offset: Duration::from_millis(0),
+            fields: &[("evidence", "this file itself is generated from a span definition")],
+        }],
+    },
+    ThesisSpan {
+        name: "swarmsh.thesis.trace_to_prompt_emergence",
+        brief: "Traces → LLM prompts (emergent).",
+        links: &["swarmsh.thesis.span_drives_code"],
+        code_filepath: "thesis_complete.py",
+        code_function: "trace_to_prompt_emergence",
+        code_lineno: 36,
+        events: &[ThesisEvent {
+            name: "prompt-synthesis",
+            ts_offset: Duration::from_millis(0),
+            fields: &[("evidence", "spans_to_prompt walks captured spans into prompt text")],
+        }],
+    },
+    ThesisSpan {
+        name: "swarmsh.thesis.telemetry_communication_channel",
+        brief: "Spans are the agent messaging bus.",
+        links: &["swarmsh.thesis.trace_to_prompt_emergence"],
+        code_filepath: "thesis_complete.py",
+        code_function: "telemetry_communication_channel",
+        code_lineno: 48,
+        events: &[ThesisEvent {
+            name: "cross-agent-correlation",
+            ts_offset: Duration::from_millis(0),
+            fields: &[("evidence", "entry/exit spans correlate messages across agents")],
+        }],
+    },
+    ThesisSpan {
+        name: "swarmsh.thesis.system_models_itself",
+        brief: "Trace graph is a live self-model.",
+        links: &["swarmsh.thesis.telemetry_communication_channel"],
+        code_filepath: "thesis_complete.py",
+        code_function: "system_models_itself",
+        code_lineno: 60,
+        events: &[ThesisEvent {
+            name: "dag-reconstruction",
+            ts_offset: Duration::from_millis(0),
+            fields: &[("evidence", "span links reconstruct this exact DAG downstream")],
+        }],
+    },
+];
+
+/// The thesis claim metadata (name, brief, links), without emitting anything.
+pub fn thesis_spans() -> &'static [ThesisSpan] {
+    THESIS_SPANS
+}
+
+/// This thread's sequence number and name, assigned once and cached for the
+/// lifetime of the thread (std's `OnceLock` stands in for
+/// `once_cell::sync::OnceCell` here since this crate doesn't otherwise
+/// depend on `once_cell`). The name is leaked to `&'static str` once per
+/// thread so every `emit_thesis_spans` call can attach it without
+/// re-allocating a `String`.
+///
+/// The sequence number is a synthetic per-process counter, not the kernel
+/// thread id — hence `swarmsh.thread.seq` rather than the `thread.id`
+/// semantic-convention key, which would wrongly imply it's comparable to a
+/// real OS tid from another instrumentation source.
+fn thread_identity() -> (u64, &'static str) {
+    thread_local! {
+        static IDENTITY: OnceLock<(u64, &'static str)> = const { OnceLock::new() };
+    }
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+    IDENTITY.with(|cell| {
+        *cell.get_or_init(|| {
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            let name = std::thread::current().name().unwrap_or("unnamed");
+            let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+            (id, name)
+        })
+    })
+}
+
+/// The per-claim attributes that don't change between calls (everything but
+/// the thread identity), built once and reused from then on so repeated
+/// `emit_thesis_spans` calls don't rebuild them. Each call still copies them
+/// into a fresh `Vec` alongside the thread attributes.
+fn static_attributes(claim: &ThesisSpan) -> &'static [KeyValue] {
+    static CACHE: OnceLock<Vec<Vec<KeyValue>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(|| {
+        THESIS_SPANS
+            .iter()
+            .map(|c| {
+                vec![
+                    KeyValue::new(BRIEF, c.brief),
+                    KeyValue::new(CODE_FILEPATH, c.code_filepath),
+                    KeyValue::new(CODE_FUNCTION, c.code_function),
+                    KeyValue::new(CODE_LINENO, c.code_lineno as i64),
+                ]
+            })
+            .collect()
+    });
+    let index = THESIS_SPANS
+        .iter()
+        .position(|c| c.name == claim.name)
+        .expect("claim must be one of THESIS_SPANS");
+    &cache[index]
+}
+
+/// Links pointing from `span` back to each of its parents, resolved against
+/// span contexts already captured earlier in this call.
+fn links_for(span: &ThesisSpan, contexts: &HashMap<&str, SpanContext>) -> Vec<Link> {
+    span.links
+        .iter()
+        .filter_map(|from| contexts.get(from))
+        .map(|ctx| Link::new(ctx.clone(), vec![KeyValue::new(EDGE, "derives")]))
+        .collect()
+}
 
 pub fn emit_thesis_spans(tracer: &dyn Tracer) {
-    let swarmsh_thesis_telemetry_as_system = tracer
-        .span_builder("swarmsh.thesis.telemetry_as_system")
-        .with_attributes(vec![
-            KeyValue::new("brief", "Telemetry is the system, not an add-on."),
-        ])
-        .start(tracer);
-    swarmsh_thesis_telemetry_as_system.end();
-
-    let swarmsh_thesis_span_drives_code = tracer
-        .span_builder("swarmsh.thesis.span_drives_code")
-        .with_attributes(vec![
-            KeyValue::new("brief", "Spans generate code & CLI."),
-        ])
-        .start(tracer);
-    swarmsh_thesis_span_drives_code.end();
-
-    let swarmsh_thesis_trace_to_prompt_emergence = tracer
-        .span_builder("swarmsh.thesis.trace_to_prompt_emergence")
-        .with_attributes(vec![
-            KeyValue::new("brief", "Traces → LLM prompts (emergent)."),
-        ])
-        .start(tracer);
-    swarmsh_thesis_trace_to_prompt_emergence.end();
-
-    let swarmsh_thesis_telemetry_communication_channel = tracer
-        .span_builder("swarmsh.thesis.telemetry_communication_channel")
-        .with_attributes(vec![
-            KeyValue::new("brief", "Spans are the agent messaging bus."),
-        ])
-        .start(tracer);
-    swarmsh_thesis_telemetry_communication_channel.end();
-
-    let swarmsh_thesis_system_models_itself = tracer
-        .span_builder("swarmsh.thesis.system_models_itself")
-        .with_attributes(vec![
-            KeyValue::new("brief", "Trace graph is a live self-model."),
-        ])
-        .start(tracer);
-    swarmsh_thesis_system_models_itself.end();
-
-}
\ No newline at end of file
+    let mut contexts: HashMap<&str, SpanContext> = HashMap::new();
+    let (thread_id, thread_name) = thread_identity();
+
+    for claim in THESIS_SPANS {
+        let start_time = std::time::SystemTime::now();
+        let mut attributes = static_attributes(claim).to_vec();
+        attributes.push(KeyValue::new(THREAD_SEQ, thread_id as i64));
+        attributes.push(KeyValue::new(THREAD_NAME, thread_name));
+
+        let mut span = tracer
+            .span_builder(claim.name)
+            .with_start_time(start_time)
+            .with_attributes(attributes)
+            .with_links(links_for(claim, &contexts))
+            .start(tracer);
+        contexts.insert(claim.name, span.span_context().clone());
+
+        for event in claim.events {
+            let fields = event
+                .fields
+                .iter()
+                .map(|(key, value)| KeyValue::new(*key, *value))
+                .collect();
+            span.add_event_with_timestamp(event.name, start_time + event.ts_offset, fields);
+        }
+
+        span.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use opentelemetry::trace::{TraceResult, TracerProvider as _};
+    use opentelemetry::Context;
+    use opentelemetry_sdk::export::trace::SpanData;
+    use opentelemetry_sdk::trace::{Span, SpanProcessor, TracerProvider};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct RecordingProcessor {
+        recorded: Arc<Mutex<Vec<SpanData>>>,
+    }
+
+    impl SpanProcessor for RecordingProcessor {
+        fn on_start(&self, _span: &mut Span, _cx: &Context) {}
+
+        fn on_end(&self, span: SpanData) {
+            self.recorded.lock().unwrap().push(span);
+        }
+
+        fn force_flush(&self) -> TraceResult<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> TraceResult<()> {
+            Ok(())
+        }
+    }
+
+    fn emit_and_capture() -> Vec<SpanData> {
+        let processor = RecordingProcessor::default();
+        let recorded = processor.recorded.clone();
+        let provider = TracerProvider::builder()
+            .with_span_processor(processor)
+            .build();
+        let tracer = provider.tracer("swarmsh.thesis.tests");
+
+        emit_thesis_spans(&tracer);
+        let _ = provider.shutdown();
+
+        recorded.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn events_use_their_own_name_and_offset_timestamp() {
+        let spans = emit_and_capture();
+        assert_eq!(spans.len(), THESIS_SPANS.len());
+
+        for claim in THESIS_SPANS {
+            let span = spans
+                .iter()
+                .find(|s| s.name.as_ref() == claim.name)
+                .unwrap_or_else(|| panic!("{} should have been emitted", claim.name));
+            assert_eq!(span.events.len(), claim.events.len());
+
+            for (recorded_event, expected) in span.events.iter().zip(claim.events.iter()) {
+                assert_eq!(recorded_event.name.as_ref(), expected.name);
+                assert_ne!(
+                    recorded_event.name.as_ref(),
+                    claim.name,
+                    "event should carry its own name, not the span's"
+                );
+                assert_eq!(recorded_event.timestamp, span.start_time + expected.ts_offset);
+            }
+        }
+    }
+
+    #[test]
+    fn links_connect_each_claim_to_its_parents() {
+        let spans = emit_and_capture();
+
+        let span_id_by_name: HashMap<&str, opentelemetry::trace::SpanId> = spans
+            .iter()
+            .map(|s| (s.name.as_ref(), s.span_context.span_id()))
+            .collect();
+
+        for claim in THESIS_SPANS {
+            let span = spans
+                .iter()
+                .find(|s| s.name.as_ref() == claim.name)
+                .unwrap_or_else(|| panic!("{} should have been emitted", claim.name));
+            assert_eq!(
+                span.links.len(),
+                claim.links.len(),
+                "{} should have exactly one link per configured parent",
+                claim.name
+            );
+            for parent_name in claim.links {
+                let parent_id = span_id_by_name[parent_name];
+                assert!(
+                    span.links
+                        .iter()
+                        .any(|link| link.span_context.span_id() == parent_id),
+                    "{} should link back to {parent_name}",
+                    claim.name
+                );
+            }
+        }
+    }
+}